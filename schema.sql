@@ -12,7 +12,15 @@ CREATE TABLE IF NOT EXISTS users (
     email_verified BOOLEAN DEFAULT FALSE,
     streak_days INTEGER DEFAULT 0,
     last_activity_date DATE,
-    total_xp INTEGER DEFAULT 0
+    total_xp INTEGER DEFAULT 0,
+    is_admin BOOLEAN DEFAULT FALSE,
+    -- Study reminders: local time zone and hour, what to send, and when it was last sent
+    timezone VARCHAR(64) NOT NULL DEFAULT 'UTC',
+    reminder_hour INTEGER NOT NULL DEFAULT 18 CHECK (reminder_hour BETWEEN 0 AND 23),
+    streak_reminders BOOLEAN NOT NULL DEFAULT TRUE,
+    inactivity_reminder_days INTEGER DEFAULT 7,
+    last_reminder_at TIMESTAMP WITH TIME ZONE,
+    last_inactivity_reminder_at TIMESTAMP WITH TIME ZONE
 );
 
 -- User progress tracking
@@ -28,17 +36,145 @@ CREATE TABLE IF NOT EXISTS user_progress (
     UNIQUE(user_id, section_id)
 );
 
+-- Issued certificates, with a snapshot of the results they were issued for
+CREATE TABLE IF NOT EXISTS certificates (
+    id VARCHAR(32) PRIMARY KEY,          -- random, e.g. YAVIN-7KQ2-M9XD-4TPA-H3WN
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    track VARCHAR(64) NOT NULL,          -- id from content/certificates.yaml
+    recipient_name VARCHAR(255) NOT NULL,
+    title VARCHAR(255) NOT NULL,
+    description TEXT NOT NULL,
+    total_xp INTEGER NOT NULL,
+    average_quiz_score INTEGER NOT NULL,
+    sections TEXT NOT NULL,              -- JSON: [{id, title, quiz_score}]
+    issued_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    revoked_at TIMESTAMP WITH TIME ZONE,
+    UNIQUE(user_id, track)
+);
+
+-- Every graded quiz answer
+CREATE TABLE IF NOT EXISTS quiz_answers (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    section_id VARCHAR(50) NOT NULL,
+    question_id VARCHAR(50) NOT NULL,
+    response TEXT,             -- the learner's answer as JSON
+    correct BOOLEAN NOT NULL,
+    answered_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+-- Per-concept mastery (Elo rating against question difficulty)
+CREATE TABLE IF NOT EXISTS concept_mastery (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    concept VARCHAR(100) NOT NULL,
+    rating DOUBLE PRECISION NOT NULL DEFAULT 1500,
+    answers INTEGER NOT NULL DEFAULT 0,
+    correct INTEGER NOT NULL DEFAULT 0,
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    PRIMARY KEY (user_id, concept)
+);
+
+-- Spaced-repetition cards (SM-2): missed quiz questions and glossary terms
+CREATE TABLE IF NOT EXISTS review_cards (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    kind VARCHAR(20) NOT NULL CHECK (kind IN ('question', 'term')),
+    card_ref VARCHAR(255) NOT NULL,
+    ease DOUBLE PRECISION NOT NULL DEFAULT 2.5,
+    interval_days INTEGER NOT NULL DEFAULT 0,
+    repetitions INTEGER NOT NULL DEFAULT 0,
+    lapses INTEGER NOT NULL DEFAULT 0,
+    due_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    last_reviewed_at TIMESTAMP WITH TIME ZONE,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    UNIQUE(user_id, kind, card_ref)
+);
+
+-- The tab currently counting each user's reading time (see the heartbeat endpoint)
+CREATE TABLE IF NOT EXISTS reading_clocks (
+    user_id UUID PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    tab_id VARCHAR(64) NOT NULL,
+    section_id VARCHAR(50) NOT NULL,
+    last_beat_at TIMESTAMP WITH TIME ZONE NOT NULL
+);
+
+-- Server-measured reading time per user, section and day
+CREATE TABLE IF NOT EXISTS reading_time_daily (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    section_id VARCHAR(50) NOT NULL,
+    day DATE NOT NULL,
+    seconds INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (user_id, section_id, day)
+);
+
+-- Progress on each unit (### part) of a lesson; completing all of them completes the section
+CREATE TABLE IF NOT EXISTS user_unit_progress (
+    user_id UUID NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    section_id VARCHAR(50) NOT NULL,
+    unit_id VARCHAR(255) NOT NULL,
+    completed BOOLEAN NOT NULL DEFAULT FALSE,
+    completed_at TIMESTAMP WITH TIME ZONE,
+    last_viewed_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, section_id, unit_id)
+);
+
 -- Newsletter subscribers
 CREATE TABLE IF NOT EXISTS newsletter_subscribers (
     id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
     email VARCHAR(255) UNIQUE NOT NULL,
     subscribed_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
     source VARCHAR(50) DEFAULT 'website',
-    confirmed BOOLEAN DEFAULT FALSE,
+    confirmed BOOLEAN DEFAULT FALSE,     -- set by the link in the confirmation email
+    confirmed_at TIMESTAMP WITH TIME ZONE,
+    confirmation_sent_at TIMESTAMP WITH TIME ZONE,
     unsubscribed BOOLEAN DEFAULT FALSE,
     unsubscribed_at TIMESTAMP WITH TIME ZONE
 );
 
+-- Newsletter issues, drafted in Markdown by admins
+CREATE TABLE IF NOT EXISTS newsletter_campaigns (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    subject VARCHAR(255) NOT NULL,
+    markdown TEXT NOT NULL,
+    status VARCHAR(16) NOT NULL DEFAULT 'draft' CHECK (status IN ('draft', 'sending', 'sent')),
+    site_url TEXT,                       -- origin for links in the emails, set when sending starts
+    created_by UUID REFERENCES users(id) ON DELETE SET NULL,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    send_started_at TIMESTAMP WITH TIME ZONE,
+    sent_at TIMESTAMP WITH TIME ZONE
+);
+
+-- One row per campaign and recipient, worked through by the background sender
+CREATE TABLE IF NOT EXISTS newsletter_deliveries (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    campaign_id UUID NOT NULL REFERENCES newsletter_campaigns(id) ON DELETE CASCADE,
+    subscriber_id UUID NOT NULL REFERENCES newsletter_subscribers(id) ON DELETE CASCADE,
+    email VARCHAR(255) NOT NULL,
+    status VARCHAR(16) NOT NULL DEFAULT 'pending' CHECK (status IN ('pending', 'sending', 'sent', 'failed', 'skipped')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    error TEXT,
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    sent_at TIMESTAMP WITH TIME ZONE,
+    UNIQUE(campaign_id, subscriber_id)
+);
+
+-- Background job queue (see "Background jobs" in the README)
+CREATE TABLE IF NOT EXISTS jobs (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    kind VARCHAR(64) NOT NULL,
+    payload TEXT NOT NULL,
+    status VARCHAR(16) NOT NULL DEFAULT 'queued' CHECK (status IN ('queued', 'running', 'done', 'dead')),
+    attempts INTEGER NOT NULL DEFAULT 0,
+    max_attempts INTEGER NOT NULL DEFAULT 5,
+    run_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    locked_at TIMESTAMP WITH TIME ZONE,
+    unique_key VARCHAR(255),
+    last_error TEXT,
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW(),
+    finished_at TIMESTAMP WITH TIME ZONE
+);
+
 -- User achievements/badges
 CREATE TABLE IF NOT EXISTS user_achievements (
     id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
@@ -68,6 +204,62 @@ CREATE TABLE IF NOT EXISTS feedback (
     rating INTEGER CHECK (rating >= 1 AND rating <= 5),
     message TEXT NOT NULL,
     page_url VARCHAR(500),
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    -- Triage
+    status VARCHAR(16) NOT NULL DEFAULT 'new' CHECK (status IN ('new', 'in_progress', 'resolved')),
+    tags TEXT[] NOT NULL DEFAULT '{}',
+    ip_address VARCHAR(45),
+    updated_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- Admins' internal notes on feedback, and replies emailed to the submitter (reply_to set)
+CREATE TABLE IF NOT EXISTS feedback_notes (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    feedback_id UUID NOT NULL REFERENCES feedback(id) ON DELETE CASCADE,
+    author_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    body TEXT NOT NULL,
+    reply_to VARCHAR(255),
+    created_at TIMESTAMP WITH TIME ZONE NOT NULL DEFAULT NOW()
+);
+
+-- AI tutor conversations (owned by a user, or by an anonymous session id)
+CREATE TABLE IF NOT EXISTS chat_conversations (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    user_id UUID REFERENCES users(id) ON DELETE CASCADE,
+    anon_id UUID,
+    title VARCHAR(255) NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
+    updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+-- AI tutor conversation turns
+CREATE TABLE IF NOT EXISTS chat_messages (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    conversation_id UUID NOT NULL REFERENCES chat_conversations(id) ON DELETE CASCADE,
+    role VARCHAR(16) NOT NULL CHECK (role IN ('user', 'assistant')),
+    content TEXT NOT NULL,
+    created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
+);
+
+-- Daily AI tutor request counters, keyed by 'user:<uuid>' or 'ip:<address>'
+CREATE TABLE IF NOT EXISTS chat_usage (
+    day DATE NOT NULL,
+    subject VARCHAR(100) NOT NULL,
+    request_count INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (day, subject)
+);
+
+-- Tutor exchanges flagged by moderation, for admin review
+CREATE TABLE IF NOT EXISTS chat_flags (
+    id UUID PRIMARY KEY DEFAULT gen_random_uuid(),
+    conversation_id UUID REFERENCES chat_conversations(id) ON DELETE SET NULL,
+    user_id UUID REFERENCES users(id) ON DELETE SET NULL,
+    ip_address VARCHAR(45),
+    direction VARCHAR(10) NOT NULL CHECK (direction IN ('input', 'output')),
+    reason VARCHAR(255) NOT NULL,
+    blocked BOOLEAN NOT NULL DEFAULT FALSE,
+    user_message TEXT NOT NULL,
+    assistant_message TEXT,
     created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
 );
 
@@ -78,6 +270,19 @@ CREATE INDEX IF NOT EXISTS idx_user_achievements_user_id ON user_achievements(us
 CREATE INDEX IF NOT EXISTS idx_user_sessions_user_id ON user_sessions(user_id);
 CREATE INDEX IF NOT EXISTS idx_user_sessions_expires_at ON user_sessions(expires_at);
 CREATE INDEX IF NOT EXISTS idx_newsletter_email ON newsletter_subscribers(email);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_newsletter_email_lower ON newsletter_subscribers (lower(email));
+CREATE INDEX IF NOT EXISTS idx_newsletter_deliveries_status ON newsletter_deliveries(status, updated_at);
+CREATE INDEX IF NOT EXISTS idx_jobs_due ON jobs(run_at) WHERE status IN ('queued', 'running');
+CREATE UNIQUE INDEX IF NOT EXISTS idx_jobs_unique_key ON jobs(unique_key) WHERE status = 'queued';
+CREATE INDEX IF NOT EXISTS idx_chat_conversations_user_id ON chat_conversations(user_id);
+CREATE INDEX IF NOT EXISTS idx_chat_conversations_anon_id ON chat_conversations(anon_id);
+CREATE INDEX IF NOT EXISTS idx_chat_messages_conversation_id ON chat_messages(conversation_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_chat_flags_created_at ON chat_flags(created_at);
+CREATE INDEX IF NOT EXISTS idx_feedback_status ON feedback(status, created_at);
+CREATE INDEX IF NOT EXISTS idx_feedback_ip_address ON feedback(ip_address, created_at);
+CREATE INDEX IF NOT EXISTS idx_feedback_notes_feedback_id ON feedback_notes(feedback_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_quiz_answers_user_id ON quiz_answers(user_id, section_id);
+CREATE INDEX IF NOT EXISTS idx_review_cards_due ON review_cards(user_id, due_at);
 
 -- Function to update updated_at timestamp
 CREATE OR REPLACE FUNCTION update_updated_at_column()
@@ -95,6 +300,9 @@ CREATE TRIGGER update_users_updated_at
     FOR EACH ROW
     EXECUTE FUNCTION update_updated_at_column();
 
+-- Grant admin access (flag log, and other /api/admin endpoints):
+-- UPDATE users SET is_admin = TRUE WHERE email = 'you@example.com';
+
 -- Achievement definitions (for reference)
 -- These are defined in code but listed here for documentation
 /*
@@ -119,64 +119,250 @@ const YavinProgress = {
                 YavinAuth.user.total_xp = data.total_xp;
                 YavinAuth.updateUI();
             }
+        } else if (data.error) {
+            showToast(data.error);
         }
         return data;
     },
     
-    async submitQuizToServer(section, score, total) {
+    // Tracks reading through the lesson's units (its ### parts): a unit is done once the
+    // next one, or the page navigation after the last, scrolls into view.
+    async trackUnits(sectionId) {
+        const response = await fetch(`/api/progress/${encodeURIComponent(sectionId)}`);
+        if (!response.ok) return;
+        const progress = await response.json();
+        if (!progress.units.length) return;
+        
+        const headings = progress.units.map(u => document.getElementById(u.id));
+        const endMarker = document.querySelector('.page-navigation');
+        const completed = new Set(progress.units.filter(u => u.completed).map(u => u.id));
+        let current = null;
+        
+        const send = async (unitId, isComplete) => {
+            const res = await fetch('/api/progress/unit', {
+                method: 'POST',
+                headers: { 'Content-Type': 'application/json' },
+                body: JSON.stringify({ section_id: sectionId, unit_id: unitId, completed: isComplete })
+            });
+            const data = await res.json();
+            if (data.xp_earned > 0) {
+                showToast(`Section complete! +${data.xp_earned} XP earned!`);
+                YavinAuth.user.total_xp = data.total_xp;
+                YavinAuth.updateUI();
+            }
+        };
+        
+        const observer = new IntersectionObserver(entries => {
+            entries.filter(e => e.isIntersecting).forEach(entry => {
+                const index = headings.indexOf(entry.target);
+                // Reaching a unit (or the end of the page) finishes the one before it
+                const finished = index === -1 ? progress.units.length - 1 : index - 1;
+                if (finished >= 0 && !completed.has(progress.units[finished].id)) {
+                    completed.add(progress.units[finished].id);
+                    send(progress.units[finished].id, true);
+                } else if (index >= 0 && current !== index) {
+                    send(progress.units[index].id, false);
+                }
+                if (index >= 0) current = index;
+            });
+        });
+        headings.filter(Boolean).forEach(h => observer.observe(h));
+        if (endMarker) observer.observe(endMarker);
+        
+        // Offer to jump back to where the learner left off
+        const first = progress.units[0];
+        if (progress.resume && progress.resume.id !== first.id && !window.location.hash && !progress.completed) {
+            const title = document.querySelector('#main-content .section-title');
+            if (title) {
+                const banner = document.createElement('div');
+                banner.className = 'resume-banner';
+                banner.innerHTML = `Continue where you left off: <a href="#${progress.resume.id}"></a>`;
+                banner.querySelector('a').textContent = progress.resume.title;
+                title.insertAdjacentElement('afterend', banner);
+            }
+        }
+    },
+    
+    async submitQuizToServer(section, answers) {
         const response = await fetch('/api/quiz', {
             method: 'POST',
             headers: { 'Content-Type': 'application/json' },
-            body: JSON.stringify({ section, score, total })
+            body: JSON.stringify({ section, answers })
         });
         return await response.json();
     }
 };
 
 // ============================================================================
-// Quiz System
+// Reading Time
 // ============================================================================
 
-async function submitQuiz(sectionId) {
-    const container = document.querySelector(`.quiz-container[data-section="${sectionId}"]`);
-    if (!container) return;
-    
-    const questions = container.querySelectorAll('.quiz-question');
-    const submitBtn = container.querySelector('.quiz-submit');
-    const resultsDiv = container.querySelector('.quiz-results');
-    
-    let score = 0;
-    let total = questions.length;
-    let unanswered = 0;
+// Pings the server while the lesson is visible; the server decides how much time counts.
+const YavinReading = {
+    intervalMs: 15000,
+    // Without scrolling, typing or pointer movement for this long, the reader counts as idle
+    idleAfterMs: 60000,
+    lastInteraction: Date.now(),
+    timer: null,
+    
+    tabId() {
+        let id = sessionStorage.getItem('yavinTabId');
+        if (!id) {
+            id = Math.random().toString(36).slice(2) + Date.now().toString(36);
+            sessionStorage.setItem('yavinTabId', id);
+        }
+        return id;
+    },
     
-    // Check each question
-    questions.forEach(question => {
-        const selected = question.querySelector('input[type="radio"]:checked');
-        const options = question.querySelectorAll('.quiz-option');
-        
-        if (!selected) {
-            unanswered++;
-            return;
+    async beat(sectionId) {
+        if (document.visibilityState !== 'visible') return;
+        try {
+            const response = await fetch('/api/reading/heartbeat', {
+                method: 'POST',
+                headers: { 'Content-Type': 'application/json' },
+                body: JSON.stringify({
+                    section_id: sectionId,
+                    tab_id: this.tabId(),
+                    active: Date.now() - this.lastInteraction < this.idleAfterMs
+                })
+            });
+            const data = await response.json();
+            if (data.interval_seconds) this.intervalMs = data.interval_seconds * 1000;
+        } catch (e) {
+            // Missed beats just aren't counted
         }
+    },
+    
+    start(sectionId) {
+        const touch = () => { this.lastInteraction = Date.now(); };
+        ['scroll', 'keydown', 'mousemove', 'touchstart'].forEach(type => {
+            window.addEventListener(type, touch, { passive: true });
+        });
         
-        const isCorrect = selected.dataset.correct === 'true';
+        const schedule = () => {
+            clearTimeout(this.timer);
+            this.timer = setTimeout(async () => {
+                await this.beat(sectionId);
+                schedule();
+            }, this.intervalMs);
+        };
+        document.addEventListener('visibilitychange', () => {
+            if (document.visibilityState === 'visible') {
+                touch();
+                this.beat(sectionId);
+                schedule();
+            } else {
+                clearTimeout(this.timer);
+            }
+        });
         
-        // Mark options
-        options.forEach(option => {
-            const input = option.querySelector('input[type="radio"]');
+        this.beat(sectionId);
+        schedule();
+    }
+};
+
+// ============================================================================
+// Quiz System
+// ============================================================================
+
+// The learner's answer to one question, or null if it is unanswered
+function quizResponse(question) {
+    switch (question.dataset.type) {
+        case 'multiple': {
+            const checked = [...question.querySelectorAll('input[type="checkbox"]:checked')];
+            return checked.length ? checked.map(input => Number(input.value)) : null;
+        }
+        case 'numeric': {
+            const value = question.querySelector('.quiz-input').value;
+            return value === '' ? null : Number(value);
+        }
+        case 'short': {
+            const value = question.querySelector('.quiz-input').value.trim();
+            return value || null;
+        }
+        case 'ordering':
+            return [...question.querySelectorAll('.quiz-order-item')].map(item => Number(item.dataset.item));
+        default: {
+            const selected = question.querySelector('input[type="radio"]:checked');
+            return selected ? Number(selected.value) : null;
+        }
+    }
+}
+
+// Shows the server's grading on a question and locks it
+function markQuizQuestion(question, result) {
+    const type = question.dataset.type;
+    
+    if (type === 'single' || type === 'multiple') {
+        const answers = [].concat(result.answer);
+        question.querySelectorAll('.quiz-option').forEach(option => {
+            const input = option.querySelector('input');
             option.style.position = 'relative';
             
-            if (input.dataset.correct === 'true') {
+            if (answers.includes(Number(input.value))) {
                 option.classList.add('correct');
-            } else if (input.checked && !isCorrect) {
+            } else if (input.checked) {
                 option.classList.add('incorrect');
             }
             
             // Disable further changes
             input.disabled = true;
         });
-        
-        if (isCorrect) score++;
+    } else {
+        const answer = question.querySelector('.quiz-answer, .quiz-ordering');
+        answer.classList.add(result.correct ? 'correct' : 'incorrect');
+        question.querySelectorAll('input, button').forEach(el => { el.disabled = true; });
+        
+        if (!result.correct && type === 'ordering') {
+            // Show the items in the right order
+            const list = question.querySelector('.quiz-ordering');
+            result.answer.forEach(index => {
+                list.appendChild(list.querySelector(`.quiz-order-item[data-item="${index}"]`));
+            });
+        } else if (!result.correct) {
+            const correction = document.createElement('p');
+            correction.className = 'quiz-correction';
+            correction.textContent = `Answer: ${result.answer}`;
+            question.appendChild(correction);
+        }
+    }
+    
+    if (result.explanation) {
+        const explanation = document.createElement('p');
+        explanation.className = 'quiz-explanation';
+        explanation.textContent = result.explanation;
+        question.appendChild(explanation);
+    }
+}
+
+// Moves an ordering item up (-1) or down (1)
+function moveQuizItem(button, direction) {
+    const item = button.closest('.quiz-order-item');
+    if (direction < 0 && item.previousElementSibling) {
+        item.parentNode.insertBefore(item, item.previousElementSibling);
+    } else if (direction > 0 && item.nextElementSibling) {
+        item.parentNode.insertBefore(item.nextElementSibling, item);
+    }
+}
+
+async function submitQuiz(sectionId) {
+    const container = document.querySelector(`.quiz-container[data-section="${sectionId}"]`);
+    if (!container) return;
+    
+    const questions = container.querySelectorAll('.quiz-question');
+    const submitBtn = container.querySelector('.quiz-submit');
+    const resultsDiv = container.querySelector('.quiz-results');
+    
+    // Collect the answer to each question
+    const answers = {};
+    let unanswered = 0;
+    questions.forEach(question => {
+        const response = quizResponse(question);
+        if (response !== null) {
+            answers[question.dataset.question] = response;
+        } else {
+            unanswered++;
+        }
     });
     
     // Check if all questions answered
@@ -185,11 +371,19 @@ async function submitQuiz(sectionId) {
         return;
     }
     
-    // Calculate percentage
-    const percentage = Math.round((score / total) * 100);
+    // The server grades the quiz; the answers are not in the page
+    const serverResult = await YavinProgress.submitQuizToServer(sectionId, answers);
+    if (serverResult.error) {
+        showToast(serverResult.error);
+        return;
+    }
+    const { score, total, percentage } = serverResult;
     
-    // Submit to server if logged in
-    const serverResult = await YavinProgress.submitQuizToServer(sectionId, score, total);
+    // Mark answers
+    (serverResult.results || []).forEach(result => {
+        const question = container.querySelector(`.quiz-question[data-question="${result.question_id}"]`);
+        if (question) markQuizQuestion(question, result);
+    });
     
     // Update UI
     submitBtn.disabled = true;
@@ -219,6 +413,7 @@ async function submitQuiz(sectionId) {
         <p class="quiz-percentage">${percentage}%</p>
         <p class="quiz-feedback">${feedbackMessage}</p>
         ${serverResult.bonus_xp ? `<p class="quiz-bonus">+${serverResult.bonus_xp} bonus XP for perfect score!</p>` : ''}
+        ${serverResult.review_cards_added ? `<p class="quiz-review-hint">${serverResult.review_cards_added} missed question${serverResult.review_cards_added > 1 ? 's' : ''} added to your review queue.</p>` : ''}
         ${!serverResult.logged_in ? '<p class="quiz-login-hint">Sign in to save your progress and earn XP!</p>' : ''}
     `;
     resultsDiv.style.display = 'block';
@@ -308,6 +503,10 @@ function toggleAIChat() {
     const chatWindow = document.getElementById('aiChatWindow');
     if (chatWindow.style.display === 'none' || chatWindow.style.display === '') {
         chatWindow.style.display = 'flex';
+        if (YavinChat.conversationId && !chatWindow.dataset.resumed) {
+            chatWindow.dataset.resumed = 'true';
+            YavinChat.resume(YavinChat.conversationId);
+        }
         document.getElementById('aiChatInput')?.focus();
     } else {
         chatWindow.style.display = 'none';
@@ -329,6 +528,121 @@ function addChatMessage(text, type) {
     return messageDiv;
 }
 
+const YavinChat = {
+    conversationId: localStorage.getItem('yavinChatConversation'),
+    
+    setConversation(id) {
+        this.conversationId = id;
+        if (id) {
+            localStorage.setItem('yavinChatConversation', id);
+        } else {
+            localStorage.removeItem('yavinChatConversation');
+        }
+    },
+    
+    newConversation() {
+        this.setConversation(null);
+        this.hideHistory();
+        const messagesDiv = document.getElementById('aiChatMessages');
+        messagesDiv.innerHTML = '';
+        addChatMessage('👋 New conversation started. What would you like to learn about?', 'ai');
+    },
+    
+    async resume(id) {
+        try {
+            const response = await fetch(`/api/chat/conversations/${id}`);
+            if (!response.ok) {
+                this.setConversation(null);
+                return;
+            }
+            const data = await response.json();
+            this.setConversation(id);
+            const messagesDiv = document.getElementById('aiChatMessages');
+            messagesDiv.innerHTML = '';
+            data.messages.forEach(m => addChatMessage(m.content, m.role === 'user' ? 'user' : 'ai'));
+            this.hideHistory();
+        } catch (e) {
+            showToast('Could not load that conversation');
+        }
+    },
+    
+    async remove(id) {
+        await fetch(`/api/chat/conversations/${id}`, { method: 'DELETE' });
+        if (this.conversationId === id) {
+            this.newConversation();
+        }
+        await this.showHistory();
+    },
+    
+    async showHistory() {
+        const historyDiv = document.getElementById('aiChatHistory');
+        const messagesDiv = document.getElementById('aiChatMessages');
+        historyDiv.innerHTML = '<p class="ai-chat-history-empty">Loading...</p>';
+        historyDiv.style.display = 'flex';
+        messagesDiv.style.display = 'none';
+        
+        try {
+            const response = await fetch('/api/chat/conversations');
+            const data = await response.json();
+            const conversations = data.conversations || [];
+            historyDiv.innerHTML = '';
+            if (conversations.length === 0) {
+                historyDiv.innerHTML = '<p class="ai-chat-history-empty">No past conversations yet.</p>';
+                return;
+            }
+            conversations.forEach(c => {
+                const item = document.createElement('div');
+                item.className = 'ai-chat-history-item';
+                
+                const title = document.createElement('button');
+                title.className = 'ai-chat-history-title';
+                title.textContent = c.title;
+                title.addEventListener('click', () => this.resume(c.id));
+                
+                const del = document.createElement('button');
+                del.setAttribute('aria-label', 'Delete conversation');
+                del.textContent = '🗑';
+                del.addEventListener('click', () => this.remove(c.id));
+                
+                item.appendChild(title);
+                item.appendChild(del);
+                historyDiv.appendChild(item);
+            });
+        } catch (e) {
+            historyDiv.innerHTML = '<p class="ai-chat-history-empty">Could not load conversations.</p>';
+        }
+    },
+    
+    hideHistory() {
+        document.getElementById('aiChatHistory').style.display = 'none';
+        document.getElementById('aiChatMessages').style.display = 'flex';
+    },
+    
+    toggleHistory() {
+        if (document.getElementById('aiChatHistory').style.display === 'none') {
+            this.showHistory();
+        } else {
+            this.hideHistory();
+        }
+    }
+};
+
+function addChatSources(messageDiv, sources) {
+    if (sources.length === 0) return;
+    
+    const list = document.createElement('div');
+    list.className = 'ai-message-sources';
+    list.appendChild(document.createTextNode('Sources: '));
+    sources.forEach((source, i) => {
+        const link = document.createElement('a');
+        link.href = source.url;
+        link.textContent = source.title;
+        list.appendChild(link);
+        if (i < sources.length - 1) list.appendChild(document.createTextNode(' · '));
+    });
+    messageDiv.appendChild(list);
+}
+
 function addTypingIndicator() {
     const messagesDiv = document.getElementById('aiChatMessages');
     const indicator = document.createElement('div');
@@ -1157,6 +1471,13 @@ document.addEventListener('DOMContentLoaded', async () => {
         await YavinBadges.load();
         // Check badges on page load
         YavinBadges.checkBadges('page_load');
+        
+        // Track reading progress on lesson pages
+        const lessonId = document.body.dataset.lessonId;
+        if (lessonId) {
+            YavinProgress.trackUnits(lessonId);
+            YavinReading.start(lessonId);
+        }
     }
     
     // Search keyboard shortcut (Cmd/Ctrl + K)
@@ -1255,22 +1576,41 @@ document.addEventListener('DOMContentLoaded', async () => {
         feedbackForm.addEventListener('submit', async (e) => {
             e.preventDefault();
             const formData = new FormData(feedbackForm);
-            const response = await fetch('/api/feedback', {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify({
-                    name: formData.get('name'),
-                    email: formData.get('email'),
-                    rating: parseInt(formData.get('rating')),
-                    message: formData.get('message'),
-                    page_url: window.location.href
-                })
-            });
-            const result = await response.json();
-            if (result.success) {
-                document.getElementById('feedbackForm').style.display = 'none';
-                document.getElementById('feedbackSuccess').style.display = 'block';
+            const error = document.getElementById('feedbackError');
+            error.textContent = '';
+            try {
+                const response = await fetch('/api/feedback', {
+                    method: 'POST',
+                    headers: { 'Content-Type': 'application/json' },
+                    body: JSON.stringify({
+                        name: formData.get('name'),
+                        email: formData.get('email'),
+                        rating: parseInt(formData.get('rating')),
+                        message: formData.get('message'),
+                        page_url: window.location.href,
+                        website: formData.get('website')
+                    })
+                });
+                const result = await response.json();
+                if (!result.success) {
+                    error.textContent = result.error || 'Could not send your feedback. Please try again.';
+                    return;
+                }
+            } catch (e) {
+                error.textContent = 'Could not send your feedback. Please try again.';
+                return;
             }
+            
+            feedbackForm.style.display = 'none';
+            document.getElementById('feedbackSuccess').style.display = 'block';
+            
+            // Reset the form and close after 3 seconds
+            setTimeout(() => {
+                feedbackForm.reset();
+                feedbackForm.style.display = 'flex';
+                document.getElementById('feedbackSuccess').style.display = 'none';
+                closeFeedbackModal();
+            }, 3000);
         });
     }
     
@@ -1294,11 +1634,23 @@ document.addEventListener('DOMContentLoaded', async () => {
                 const response = await fetch('/api/chat', {
                     method: 'POST',
                     headers: { 'Content-Type': 'application/json' },
-                    body: JSON.stringify({ message })
+                    body: JSON.stringify({
+                        message,
+                        conversation_id: YavinChat.conversationId,
+                        page_id: document.body.dataset.pageId || null,
+                        section: window.location.hash.slice(1) || null
+                    })
                 });
                 const data = await response.json();
                 typingIndicator.remove();
-                addChatMessage(data.response || 'Sorry, I couldn\'t process that.', 'ai');
+                if (response.status === 404) {
+                    YavinChat.setConversation(null);
+                }
+                if (data.conversation_id) {
+                    YavinChat.setConversation(data.conversation_id);
+                }
+                const reply = addChatMessage(data.response || data.error || 'Sorry, I couldn\'t process that.', 'ai');
+                addChatSources(reply, data.sources || []);
             } catch (e) {
                 typingIndicator.remove();
                 addChatMessage('Connection error. Please try again.', 'ai');
@@ -1477,32 +1829,79 @@ const YavinSearch = {
         }
         
         try {
-            const response = await fetch(`/api/search?q=${encodeURIComponent(query)}`);
-            const data = await response.json();
-            this.showResults(data.results || []);
+            const q = encodeURIComponent(query);
+            const [searchResponse, suggestResponse] = await Promise.all([
+                fetch(`/api/search?q=${q}`),
+                fetch(`/api/search/suggest?q=${q}`)
+            ]);
+            const data = await searchResponse.json();
+            const suggestData = await suggestResponse.json();
+            this.showResults(data.results || [], suggestData.suggestions || [], data.corrected_query);
         } catch (e) {
             this.showResults([]);
         }
     },
     
-    showResults(results) {
+    useSuggestion(text) {
+        const input = document.getElementById('searchInput');
+        if (input) {
+            input.value = text;
+            input.focus();
+        }
+        this.search(text);
+    },
+    
+    showResults(results, suggestions = [], correctedQuery = null) {
         const container = document.getElementById('searchResults');
         if (!container) return;
         
+        const escape = text => text.replace(/[&<>"']/g, c => `&#${c.charCodeAt(0)};`);
+        const suggestionHtml = suggestions.length === 0 ? '' : `
+            <div class="search-suggestions">
+                ${suggestions.map(s => s.url
+                    ? `<a href="${s.url}" class="search-suggestion" onclick="YavinSearch.close()">${escape(s.text)}</a>`
+                    : `<button type="button" class="search-suggestion" data-text="${escape(s.text)}" onclick="YavinSearch.useSuggestion(this.dataset.text)">${escape(s.text)}</button>`
+                ).join('')}
+            </div>
+        `;
+        const correctionHtml = correctedQuery
+            ? `<p class="search-correction">Showing results for <strong>${escape(correctedQuery)}</strong></p>`
+            : '';
+        
         if (results.length === 0) {
-            container.innerHTML = '<p class="search-no-results">No results found. Try different keywords.</p>';
+            container.innerHTML = suggestionHtml + '<p class="search-no-results">No results found. Try different keywords.</p>';
             return;
         }
         
-        container.innerHTML = results.map(r => `
+        container.innerHTML = suggestionHtml + correctionHtml + results.map(r => `
             <a href="${r.url}" class="search-result" onclick="YavinSearch.close()">
                 <span class="search-result-section">${r.section}</span>
                 <span class="search-result-title">${r.title}</span>
+                ${r.snippet ? `<span class="search-result-snippet">${r.snippet}</span>` : ''}
             </a>
         `).join('');
     }
 };
 
+// Give lesson headings stable ids so search results and tutor citations can link to them.
+// Must match slugify() / extract_template_chunks() in src/main.rs.
+function assignHeadingAnchors() {
+    const used = {};
+    document.querySelectorAll('#main-content h2, #main-content h3, #main-content h4').forEach(heading => {
+        if (heading.id) return;
+        const slug = heading.textContent.toLowerCase().replace(/[^a-z0-9]+/g, '-').replace(/^-+|-+$/g, '');
+        if (!slug) return;
+        used[slug] = (used[slug] || 0) + 1;
+        heading.id = used[slug] === 1 ? slug : `${slug}-${used[slug]}`;
+    });
+    
+    if (window.location.hash) {
+        document.getElementById(decodeURIComponent(window.location.hash.slice(1)))?.scrollIntoView();
+    }
+}
+
+document.addEventListener('DOMContentLoaded', assignHeadingAnchors);
+
 // ============================================================================
 // Certificate System
 // ============================================================================
@@ -1513,7 +1912,7 @@ const YavinCertificate = {
             const response = await fetch('/api/certificate');
             return await response.json();
         } catch (e) {
-            return { eligible: false };
+            return { eligible: false, tracks: [] };
         }
     },
     
@@ -1526,64 +1925,96 @@ const YavinCertificate = {
         modal.classList.add('active');
         
         const data = await this.check();
-        
-        if (!data.eligible) {
-            content.innerHTML = `
-                <div class="certificate-not-eligible">
-                    <h4>Keep Learning!</h4>
-                    <p>Complete all sections to earn your certificate.</p>
-                    <div class="certificate-progress">
-                        <div class="progress-bar">
-                            <div class="progress-fill" style="width: ${(data.completed / data.total) * 100}%"></div>
-                        </div>
-                        <span>${data.completed}/${data.total} sections completed</span>
-                    </div>
-                    ${data.remaining ? `<p class="remaining-sections">Remaining: ${data.remaining.join(', ')}</p>` : ''}
+        this.tracks = data.tracks || [];
+        content.innerHTML = this.tracks
+            .map(track => track.revoked_at ? this.renderRevoked(track)
+                : track.certificate ? this.renderCertificate(track) : this.renderProgress(track))
+            .join('');
+    },
+    
+    renderProgress(track) {
+        const { sections, quiz_score: quiz, minutes } = track;
+        const requirement = (label, current, required, unit) => `
+            <div class="certificate-progress">
+                <div class="progress-bar">
+                    <div class="progress-fill" style="width: ${required ? Math.min(current / required, 1) * 100 : 100}%"></div>
                 </div>
-            `;
-            return;
-        }
-        
-        const cert = data.certificate;
-        content.innerHTML = `
-            <div class="certificate-preview" id="certificatePreview">
-                <div class="certificate-border">
-                    <div class="certificate-header">
-                        <img src="/static/yavin-logo.png" alt="Yavin" class="certificate-logo">
-                        <h2>Certificate of Completion</h2>
-                    </div>
-                    <div class="certificate-body">
-                        <p class="certificate-awarded">This is to certify that</p>
-                        <h3 class="certificate-name">${cert.recipient_name}</h3>
-                        <p class="certificate-text">${cert.description}</p>
-                        <div class="certificate-stats">
-                            <div class="cert-stat">
-                                <span class="cert-stat-value">${cert.total_xp}</span>
-                                <span class="cert-stat-label">Total XP</span>
-                            </div>
-                            <div class="cert-stat">
-                                <span class="cert-stat-value">${cert.average_quiz_score}%</span>
-                                <span class="cert-stat-label">Avg Quiz Score</span>
-                            </div>
-                            <div class="cert-stat">
-                                <span class="cert-stat-value">${cert.sections_completed}</span>
-                                <span class="cert-stat-label">Sections</span>
+                <span>${label}: ${current}${unit}/${required}${unit}</span>
+            </div>
+        `;
+        return `
+            <div class="certificate-track certificate-not-eligible">
+                <h4>${track.title}</h4>
+                <p>Meet every requirement to earn the ${track.certificate_title}.</p>
+                ${requirement('Sections completed', sections.completed, sections.required, '')}
+                ${quiz.required ? requirement('Average quiz score', quiz.current, quiz.required, '%') : ''}
+                ${minutes.required ? requirement('Reading time', minutes.current, minutes.required, ' min') : ''}
+                ${sections.remaining.length ? `<p class="remaining-sections">Remaining: ${sections.remaining.map(l => `<a href="${l.url}">${l.title}</a>`).join(', ')}</p>` : ''}
+            </div>
+        `;
+    },
+    
+    renderRevoked(track) {
+        const revoked = new Date(track.revoked_at).toLocaleDateString(undefined, { year: 'numeric', month: 'long', day: 'numeric' });
+        return `
+            <div class="certificate-track certificate-not-eligible">
+                <h4>${track.title}</h4>
+                <p>Your ${track.certificate_title} (ID ${track.certificate.id}) was revoked on ${revoked} and is no longer valid.</p>
+            </div>
+        `;
+    },
+    
+    renderCertificate(track) {
+        const cert = track.certificate;
+        const file = format => `/api/certificate.${format}?track=${encodeURIComponent(track.id)}`;
+        return `
+            <div class="certificate-track">
+                <div class="certificate-preview">
+                    <div class="certificate-border">
+                        <div class="certificate-header">
+                            <img src="/static/yavin-logo.png" alt="Yavin" class="certificate-logo">
+                            <h2>Certificate of Completion</h2>
+                        </div>
+                        <div class="certificate-body">
+                            <p class="certificate-awarded">This is to certify that</p>
+                            <h3 class="certificate-name">${cert.recipient_name}</h3>
+                            <p class="certificate-text">${cert.description}</p>
+                            <div class="certificate-stats">
+                                <div class="cert-stat">
+                                    <span class="cert-stat-value">${cert.total_xp}</span>
+                                    <span class="cert-stat-label">Total XP</span>
+                                </div>
+                                <div class="cert-stat">
+                                    <span class="cert-stat-value">${cert.average_quiz_score}%</span>
+                                    <span class="cert-stat-label">Avg Quiz Score</span>
+                                </div>
+                                <div class="cert-stat">
+                                    <span class="cert-stat-value">${cert.sections_completed}</span>
+                                    <span class="cert-stat-label">Sections</span>
+                                </div>
                             </div>
                         </div>
-                    </div>
-                    <div class="certificate-footer">
-                        <p class="certificate-date">Issued: ${cert.issued_date}</p>
-                        <p class="certificate-id">Certificate ID: ${cert.id}</p>
+                        <div class="certificate-footer">
+                            <p class="certificate-date">Issued: ${cert.issued_date}</p>
+                            <p class="certificate-id">Certificate ID: ${cert.id}</p>
+                            <p class="certificate-verify-link">Verify at <a href="${cert.verify_url}" target="_blank" rel="noopener">${cert.verify_url}</a></p>
+                        </div>
                     </div>
                 </div>
-            </div>
-            <div class="certificate-actions">
-                <button onclick="YavinCertificate.download()" class="btn-primary">
-                    Download Certificate
-                </button>
-                <button onclick="YavinCertificate.share()" class="btn-secondary">
-                    Share on LinkedIn
-                </button>
+                <div class="certificate-actions">
+                    <a href="${file('pdf')}" download class="btn-primary">
+                        Download PDF
+                    </a>
+                    <a href="${file('png')}" download class="btn-secondary">
+                        Download PNG
+                    </a>
+                    <button onclick="YavinCertificate.share('${track.id}')" class="btn-secondary">
+                        Share on LinkedIn
+                    </button>
+                    <a href="${cert.credential_url}" download class="btn-secondary">
+                        Download Open Badge
+                    </a>
+                </div>
             </div>
         `;
     },
@@ -1593,85 +2024,12 @@ const YavinCertificate = {
         if (modal) modal.classList.remove('active');
     },
     
-    download() {
-        // Use html2canvas to capture and download
-        const preview = document.getElementById('certificatePreview');
-        if (!preview) return;
-        
-        // Simple print-based download
-        const printWindow = window.open('', '_blank');
-        printWindow.document.write(`
-            <!DOCTYPE html>
-            <html>
-            <head>
-                <title>Yavin AI Certificate</title>
-                <style>
-                    body { 
-                        font-family: 'Georgia', serif; 
-                        margin: 0; 
-                        padding: 40px;
-                        background: white;
-                    }
-                    .certificate-border {
-                        border: 3px solid #333;
-                        padding: 40px;
-                        text-align: center;
-                    }
-                    .certificate-header h2 {
-                        font-size: 28px;
-                        margin: 20px 0;
-                    }
-                    .certificate-name {
-                        font-size: 32px;
-                        color: #333;
-                        margin: 20px 0;
-                    }
-                    .certificate-text {
-                        font-size: 14px;
-                        max-width: 500px;
-                        margin: 20px auto;
-                        line-height: 1.6;
-                    }
-                    .certificate-stats {
-                        display: flex;
-                        justify-content: center;
-                        gap: 40px;
-                        margin: 30px 0;
-                    }
-                    .cert-stat {
-                        text-align: center;
-                    }
-                    .cert-stat-value {
-                        font-size: 24px;
-                        font-weight: bold;
-                        display: block;
-                    }
-                    .cert-stat-label {
-                        font-size: 12px;
-                        color: #666;
-                    }
-                    .certificate-footer {
-                        margin-top: 30px;
-                        font-size: 12px;
-                        color: #666;
-                    }
-                    @media print {
-                        body { padding: 0; }
-                    }
-                </style>
-            </head>
-            <body>
-                ${preview.innerHTML}
-                <script>window.print(); window.close();</script>
-            </body>
-            </html>
-        `);
-        printWindow.document.close();
-    },
-    
-    share() {
-        const text = "I just earned my Yavin AI Foundations Certificate! 🎓 #AI #MachineLearning #Learning";
-        const url = "https://yavin.ai";
+    share(trackId) {
+        const track = (this.tracks || []).find(t => t.id === trackId);
+        const cert = track && track.certificate;
+        const text = `I just earned my ${cert ? cert.title : 'Yavin certificate'}! 🎓 #AI #MachineLearning #Learning`;
+        // Link to the verification page so anyone can confirm the certificate
+        const url = cert ? cert.verify_url : "https://yavin.ai";
         window.open(`https://www.linkedin.com/sharing/share-offsite/?url=${encodeURIComponent(url)}&summary=${encodeURIComponent(text)}`, '_blank');
     }
 };
@@ -1858,6 +2216,71 @@ document.addEventListener('DOMContentLoaded', () => {
     }
 });
 
+// ============================================================================
+// Study Reminders
+// ============================================================================
+
+const YavinReminders = {
+    async openModal() {
+        const modal = document.getElementById('remindersModal');
+        if (!modal) return;
+        
+        const form = document.getElementById('remindersForm');
+        const hours = form.elements.reminder_hour;
+        if (!hours.options.length) {
+            hours.innerHTML = Array.from({ length: 24 }, (_, h) =>
+                `<option value="${h}">${String(h).padStart(2, '0')}:00</option>`).join('');
+        }
+        this.setStatus('');
+        modal.classList.add('active');
+        
+        try {
+            const response = await fetch('/api/reminders');
+            const settings = await response.json();
+            form.elements.streak_reminders.checked = settings.streak_reminders;
+            form.elements.inactivity_reminder_days.value = settings.inactivity_reminder_days ?? '';
+            hours.value = settings.reminder_hour;
+            // Suggest the browser's time zone until one has been chosen
+            const browserZone = Intl.DateTimeFormat().resolvedOptions().timeZone;
+            form.elements.timezone.value = settings.timezone === 'UTC' && browserZone ? browserZone : settings.timezone;
+        } catch (e) {
+            this.setStatus('Could not load your reminder settings.');
+        }
+    },
+    
+    async save(event) {
+        event.preventDefault();
+        const form = event.target;
+        const inactivity = form.elements.inactivity_reminder_days.value;
+        try {
+            const response = await fetch('/api/reminders', {
+                method: 'PUT',
+                headers: { 'Content-Type': 'application/json' },
+                body: JSON.stringify({
+                    streak_reminders: form.elements.streak_reminders.checked,
+                    inactivity_reminder_days: inactivity ? parseInt(inactivity, 10) : null,
+                    reminder_hour: parseInt(form.elements.reminder_hour.value, 10),
+                    timezone: form.elements.timezone.value.trim()
+                })
+            });
+            const data = await response.json();
+            this.setStatus(response.ok ? 'Saved.' : data.error);
+        } catch (e) {
+            this.setStatus('Could not save your reminder settings.');
+        }
+    },
+    
+    setStatus(message) {
+        const status = document.getElementById('remindersStatus');
+        if (status) status.textContent = message;
+    },
+    
+    closeModal() {
+        const modal = document.getElementById('remindersModal');
+        if (modal) modal.classList.remove('active');
+    }
+};
+
 // Export for global access
 window.GradientDescentDemo = GradientDescentDemo;
 window.DecisionBoundaryDemo = DecisionBoundaryDemo;
@@ -1878,3 +2301,4 @@ window.CodePlayground = CodePlayground;
 window.YavinBadges = YavinBadges;
 window.YavinSearch = YavinSearch;
 window.YavinCertificate = YavinCertificate;
+window.YavinReminders = YavinReminders;
@@ -371,34 +371,6 @@ document.getElementById('feedbackModal').addEventListener('click', function(e) {
     }
 });
 
-// Handle feedback form submission
-document.getElementById('feedbackForm').addEventListener('submit', function(e) {
-    e.preventDefault();
-    
-    const formData = new FormData(this);
-    const data = {
-        name: formData.get('name') || 'Anonymous',
-        email: formData.get('email') || 'Not provided',
-        rating: formData.get('rating'),
-        message: formData.get('message')
-    };
-    
-    // Log to console (in real implementation, send to server/Google Forms)
-    console.log('Feedback submitted:', data);
-    
-    // Show success message
-    document.querySelector('.feedback-form').style.display = 'none';
-    document.getElementById('feedbackSuccess').style.display = 'block';
-    
-    // Reset form and close after 3 seconds
-    setTimeout(() => {
-        this.reset();
-        document.querySelector('.feedback-form').style.display = 'flex';
-        document.getElementById('feedbackSuccess').style.display = 'none';
-        closeFeedbackModal();
-    }, 3000);
-});
-
 // Escape key to close modal
 document.addEventListener('keydown', (e) => {
     if (e.key === 'Escape') {